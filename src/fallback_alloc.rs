@@ -63,6 +63,13 @@ where
         }
     }
 
+    fn alloc_zeroed(&mut self, layout: Layout) -> Result<MemoryBlock, AllocErr> {
+        match self.primary.alloc_zeroed(layout) {
+            primary @ Ok(_) => primary,
+            Err(_) => self.fallback.alloc_zeroed(layout),
+        }
+    }
+
     unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
         if self.primary.owns(MemoryBlock {
             ptr,
@@ -104,6 +111,41 @@ where
         }
     }
 
+    unsafe fn grow_zeroed(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        placement: ReallocPlacement,
+    ) -> Result<MemoryBlock, AllocErr> {
+        if self.primary.owns(MemoryBlock {
+            ptr,
+            size: layout.size(),
+        }) {
+            if let Ok(memory) = self.primary.grow_zeroed(ptr, layout, new_size, placement) {
+                Ok(memory)
+            } else {
+                let memory = grow(
+                    &mut self.primary,
+                    &mut self.fallback,
+                    ptr,
+                    layout,
+                    new_size,
+                    placement,
+                    AllocInit::Uninitialized,
+                )?;
+                memory
+                    .ptr
+                    .as_ptr()
+                    .add(layout.size())
+                    .write_bytes(0, new_size - layout.size());
+                Ok(memory)
+            }
+        } else {
+            self.fallback.grow_zeroed(ptr, layout, new_size, placement)
+        }
+    }
+
     unsafe fn shrink(
         &mut self,
         ptr: NonNull<u8>,
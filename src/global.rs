@@ -0,0 +1,111 @@
+use core::{
+    alloc::{AllocInit, AllocRef, GlobalAlloc, Layout, ReallocPlacement},
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Adapts an [`AllocRef`] into a [`GlobalAlloc`].
+///
+/// The wrapped allocator is stored behind a spinlock for interior mutability, since
+/// `GlobalAlloc`'s methods take `&self` while `AllocRef`'s take `&mut self`. Each `GlobalAlloc`
+/// method forwards to the corresponding `AllocRef` one, translating the result back into the
+/// pointer-based API.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(allocator_api)]
+///
+/// use alloc_compose::AsGlobal;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: AsGlobal<System> = AsGlobal::new(System);
+/// ```
+pub struct AsGlobal<A> {
+    alloc: UnsafeCell<A>,
+    locked: AtomicBool,
+}
+
+// Mirrors `unsafe impl<T: Send> Sync for Mutex<T>`: the spinlock only guarantees mutual
+// exclusion between threads, it does not make it sound to move a `!Send` allocator's state
+// across them.
+unsafe impl<A: Send> Sync for AsGlobal<A> {}
+
+/// Releases `locked` on drop, including when unwinding, so a panic inside the wrapped allocator
+/// cannot leave every other thread spinning on it forever.
+struct SpinGuard<'a> {
+    locked: &'a AtomicBool,
+}
+
+impl Drop for SpinGuard<'_> {
+    fn drop(&mut self) {
+        self.locked.store(false, Ordering::Release);
+    }
+}
+
+impl<A> AsGlobal<A> {
+    /// Creates a new `AsGlobal` wrapping `alloc`.
+    pub const fn new(alloc: A) -> Self {
+        AsGlobal {
+            alloc: UnsafeCell::new(alloc),
+            locked: AtomicBool::new(false),
+        }
+    }
+
+    fn with_alloc<R>(&self, f: impl FnOnce(&mut A) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        let _guard = SpinGuard {
+            locked: &self.locked,
+        };
+        f(unsafe { &mut *self.alloc.get() })
+    }
+}
+
+unsafe impl<A: AllocRef> GlobalAlloc for AsGlobal<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.with_alloc(|alloc| {
+            alloc
+                .alloc(layout, AllocInit::Uninitialized)
+                .map_or(core::ptr::null_mut(), |memory| memory.ptr.as_ptr())
+        })
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.with_alloc(|alloc| {
+            alloc
+                .alloc(layout, AllocInit::Zeroed)
+                .map_or(core::ptr::null_mut(), |memory| memory.ptr.as_ptr())
+        })
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.with_alloc(|alloc| {
+            alloc.dealloc(core::ptr::NonNull::new_unchecked(ptr), layout)
+        })
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.with_alloc(|alloc| {
+            let ptr = core::ptr::NonNull::new_unchecked(ptr);
+            let result = if new_size > layout.size() {
+                alloc.grow(
+                    ptr,
+                    layout,
+                    new_size,
+                    ReallocPlacement::MayMove,
+                    AllocInit::Uninitialized,
+                )
+            } else {
+                alloc.shrink(ptr, layout, new_size, ReallocPlacement::MayMove)
+            };
+            result.map_or(core::ptr::null_mut(), |memory| memory.ptr.as_ptr())
+        })
+    }
+}
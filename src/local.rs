@@ -0,0 +1,158 @@
+use crate::{AllocAll, Owns};
+use core::{
+    alloc::{AllocErr, AllocInit, AllocRef, Layout, MemoryBlock, ReallocPlacement},
+    cell::UnsafeCell,
+    ptr::NonNull,
+};
+
+/// Shares a single allocator between several collections by hiding it behind an [`UnsafeCell`].
+///
+/// `Local` stores the wrapped allocator in an [`UnsafeCell`] and implements [`AllocRef`] for
+/// `&Local<A>`, forwarding every call through `&mut *self.allocator.get()`. Any number of
+/// collections can then hold a `&Local<A>` and all route through the same underlying allocator,
+/// sharing its state (and, e.g., the statistics collected by a wrapped
+/// [`CallbackRef`][crate::CallbackRef]).
+///
+/// # Safety
+///
+/// `Local` is deliberately **not** `Sync`: the `UnsafeCell` allows the aliased `&Local<A>` handles
+/// to reach the inner allocator mutably, which is only sound as long as all access happens from a
+/// single thread. Do not attempt to share a `Local` across threads; use a `Mutex`-backed allocator
+/// instead if that is required.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(allocator_api)]
+///
+/// use alloc_compose::Local;
+/// use std::alloc::{AllocInit, AllocRef, Global, Layout};
+///
+/// let local = Local::new(Global);
+///
+/// let mut a = &local;
+/// let mut b = &local;
+///
+/// unsafe {
+///     let memory = a.alloc(Layout::new::<u32>(), AllocInit::Uninitialized)?;
+///     b.dealloc(memory.ptr, Layout::new::<u32>());
+/// }
+/// # Ok::<(), core::alloc::AllocErr>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Local<A> {
+    allocator: UnsafeCell<A>,
+}
+
+impl<A> Local<A> {
+    /// Creates a new `Local` wrapping `alloc`.
+    pub fn new(alloc: A) -> Self {
+        Local {
+            allocator: UnsafeCell::new(alloc),
+        }
+    }
+
+    /// Consumes `self`, returning the wrapped allocator.
+    pub fn into_inner(self) -> A {
+        self.allocator.into_inner()
+    }
+}
+
+unsafe impl<A: AllocRef> AllocRef for &Local<A> {
+    #[track_caller]
+    fn alloc(&mut self, layout: Layout, init: AllocInit) -> Result<MemoryBlock, AllocErr> {
+        unsafe { (*self.allocator.get()).alloc(layout, init) }
+    }
+
+    #[track_caller]
+    unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
+        (*self.allocator.get()).dealloc(ptr, layout)
+    }
+
+    #[track_caller]
+    unsafe fn grow(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        placement: ReallocPlacement,
+        init: AllocInit,
+    ) -> Result<MemoryBlock, AllocErr> {
+        (*self.allocator.get()).grow(ptr, layout, new_size, placement, init)
+    }
+
+    #[track_caller]
+    unsafe fn shrink(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        placement: ReallocPlacement,
+    ) -> Result<MemoryBlock, AllocErr> {
+        (*self.allocator.get()).shrink(ptr, layout, new_size, placement)
+    }
+}
+
+impl<A: AllocAll> AllocAll for &Local<A> {
+    #[track_caller]
+    fn alloc_all(&mut self, layout: Layout, init: AllocInit) -> Result<MemoryBlock, AllocErr> {
+        unsafe { (*self.allocator.get()).alloc_all(layout, init) }
+    }
+
+    #[track_caller]
+    fn dealloc_all(&mut self) {
+        unsafe { (*self.allocator.get()).dealloc_all() }
+    }
+
+    #[track_caller]
+    fn capacity(&self) -> usize {
+        unsafe { (*self.allocator.get()).capacity() }
+    }
+
+    #[track_caller]
+    fn capacity_left(&self) -> usize {
+        unsafe { (*self.allocator.get()).capacity_left() }
+    }
+
+    #[track_caller]
+    fn is_empty(&self) -> bool {
+        unsafe { (*self.allocator.get()).is_empty() }
+    }
+
+    #[track_caller]
+    fn is_full(&self) -> bool {
+        unsafe { (*self.allocator.get()).is_full() }
+    }
+}
+
+impl<A: Owns> Owns for &Local<A> {
+    fn owns(&self, memory: MemoryBlock) -> bool {
+        unsafe { (*self.allocator.get()).owns(memory) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Local;
+    use crate::{AllocAll, StackAlloc};
+    use std::alloc::{AllocInit, AllocRef, Layout};
+
+    #[test]
+    fn two_handles_share_the_same_allocator_state() {
+        let local = Local::new(StackAlloc::<64>::default());
+
+        let mut a = &local;
+        let mut b = &local;
+
+        unsafe {
+            a.alloc(Layout::new::<[u8; 16]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate 16 bytes through `a`");
+            b.alloc(Layout::new::<[u8; 16]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate 16 bytes through `b`");
+        }
+
+        // Both handles bumped the same underlying `StackAlloc`, so its capacity reflects the
+        // combined effect of both allocations rather than each handle's own view.
+        assert_eq!(local.into_inner().capacity_left(), 32);
+    }
+}
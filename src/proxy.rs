@@ -87,6 +87,18 @@ unsafe impl<A: AllocRef, C: CallbackRef> AllocRef for Proxy<A, C> {
         result
     }
 
+    #[track_caller]
+    fn alloc_zeroed(&mut self, layout: Layout) -> Result<MemoryBlock, AllocErr> {
+        self.callbacks.before_alloc_zeroed(layout);
+        // Also routed through `alloc`/`before_alloc`/`after_alloc`, so `CallbackRef`
+        // implementors that only observe the generic hooks (e.g. `stats::BytesTracker`) still
+        // see zeroed allocations, while implementors that care can specialize via
+        // `before_alloc_zeroed`/`after_alloc_zeroed`.
+        let result = self.alloc(layout, AllocInit::Zeroed);
+        self.callbacks.after_alloc_zeroed(layout, result);
+        result
+    }
+
     #[track_caller]
     unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
         self.callbacks.before_dealloc(ptr, layout);
@@ -111,6 +123,24 @@ unsafe impl<A: AllocRef, C: CallbackRef> AllocRef for Proxy<A, C> {
         result
     }
 
+    #[track_caller]
+    unsafe fn grow_zeroed(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        placement: ReallocPlacement,
+    ) -> Result<MemoryBlock, AllocErr> {
+        self.callbacks
+            .before_grow_zeroed(ptr, layout, new_size, placement);
+        // See the comment on `alloc_zeroed`: also routed through `grow` so existing
+        // `CallbackRef` implementors aren't blind to zeroed grows.
+        let result = self.grow(ptr, layout, new_size, placement, AllocInit::Zeroed);
+        self.callbacks
+            .after_grow_zeroed(ptr, layout, new_size, placement, result);
+        result
+    }
+
     #[track_caller]
     unsafe fn shrink(
         &mut self,
@@ -137,6 +167,16 @@ impl<A: AllocAll, C: CallbackRef> AllocAll for Proxy<A, C> {
         result
     }
 
+    #[track_caller]
+    fn alloc_all_zeroed(&mut self, layout: Layout) -> Result<MemoryBlock, AllocErr> {
+        self.callbacks.before_alloc_all_zeroed(layout);
+        // See the comment on `AllocRef::alloc_zeroed`: also routed through `alloc_all` so
+        // existing `CallbackRef` implementors aren't blind to zeroed allocations.
+        let result = self.alloc_all(layout, AllocInit::Zeroed);
+        self.callbacks.after_alloc_all_zeroed(layout, result);
+        result
+    }
+
     #[track_caller]
     fn dealloc_all(&mut self) {
         self.callbacks.before_dealloc_all();
@@ -0,0 +1,302 @@
+use crate::{AllocAll, Owns};
+use core::{
+    alloc::{AllocErr, AllocInit, AllocRef, Layout, MemoryBlock, ReallocPlacement},
+    mem::MaybeUninit,
+    ptr::NonNull,
+};
+
+/// A bump allocator that owns its storage inline as a const-generic byte array.
+///
+/// `StackAlloc<N>` allocates by advancing an offset into its own `[MaybeUninit<u8>; N]` buffer,
+/// so it can be dropped into a combinator like [`FallbackAlloc`][crate::FallbackAlloc] without a
+/// separate backing buffer to keep alive:
+///
+/// ```rust
+/// #![feature(allocator_api)]
+///
+/// use alloc_compose::{FallbackAlloc, StackAlloc};
+/// use std::alloc::System;
+///
+/// let mut alloc = FallbackAlloc {
+///     primary: StackAlloc::<4096>::default(),
+///     fallback: System,
+/// };
+/// ```
+///
+/// Allocation is a simple pointer bump, and `dealloc` only rewinds the bump pointer when the
+/// freed block is the most recently allocated one (a no-op otherwise); [`dealloc_all`] resets the
+/// arena in a single step.
+///
+/// [`dealloc_all`]: AllocAll::dealloc_all
+pub struct StackAlloc<const N: usize> {
+    data: [MaybeUninit<u8>; N],
+    offset: usize,
+}
+
+impl<const N: usize> core::fmt::Debug for StackAlloc<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // `data` holds (possibly) uninitialized bytes whose values cannot be printed, so only the
+        // bookkeeping is shown.
+        f.debug_struct("StackAlloc")
+            .field("capacity", &N)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<const N: usize> StackAlloc<N> {
+    /// Creates a new, empty `StackAlloc`.
+    pub fn new() -> Self {
+        StackAlloc {
+            data: [MaybeUninit::uninit(); N],
+            offset: 0,
+        }
+    }
+
+    fn start(&self) -> *mut u8 {
+        self.data.as_ptr() as *mut u8
+    }
+}
+
+impl<const N: usize> Default for StackAlloc<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<const N: usize> AllocRef for StackAlloc<N> {
+    fn alloc(&mut self, layout: Layout, init: AllocInit) -> Result<MemoryBlock, AllocErr> {
+        let current = unsafe { self.start().add(self.offset) };
+        let aligned_offset = self.offset + current.align_offset(layout.align());
+        let end = aligned_offset.checked_add(layout.size()).ok_or(AllocErr)?;
+        if end > N {
+            return Err(AllocErr);
+        }
+
+        self.offset = end;
+        let ptr = unsafe { NonNull::new_unchecked(self.start().add(aligned_offset)) };
+        if let AllocInit::Zeroed = init {
+            unsafe { ptr.as_ptr().write_bytes(0, layout.size()) };
+        }
+
+        Ok(MemoryBlock {
+            ptr,
+            size: layout.size(),
+        })
+    }
+
+    unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
+        if ptr.as_ptr().add(layout.size()) as *const u8 == self.start().add(self.offset) {
+            self.offset -= layout.size();
+        }
+    }
+
+    unsafe fn grow(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        placement: ReallocPlacement,
+        init: AllocInit,
+    ) -> Result<MemoryBlock, AllocErr> {
+        debug_assert!(new_size >= layout.size());
+
+        let is_last = ptr.as_ptr().add(layout.size()) as *const u8 == self.start().add(self.offset);
+        if is_last {
+            let additional = new_size - layout.size();
+            let end = self.offset.checked_add(additional).ok_or(AllocErr)?;
+            if end <= N {
+                self.offset = end;
+                if let AllocInit::Zeroed = init {
+                    ptr.as_ptr()
+                        .add(layout.size())
+                        .write_bytes(0, additional);
+                }
+                return Ok(MemoryBlock { ptr, size: new_size });
+            }
+        }
+
+        if let ReallocPlacement::InPlace = placement {
+            return Err(AllocErr);
+        }
+
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_memory = self.alloc(new_layout, AllocInit::Uninitialized)?;
+        core::ptr::copy_nonoverlapping(ptr.as_ptr(), new_memory.ptr.as_ptr(), layout.size());
+        if let AllocInit::Zeroed = init {
+            new_memory
+                .ptr
+                .as_ptr()
+                .add(layout.size())
+                .write_bytes(0, new_size - layout.size());
+        }
+        self.dealloc(ptr, layout);
+        Ok(new_memory)
+    }
+
+    unsafe fn shrink(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+        _placement: ReallocPlacement,
+    ) -> Result<MemoryBlock, AllocErr> {
+        debug_assert!(new_size <= layout.size());
+
+        if ptr.as_ptr().add(layout.size()) as *const u8 == self.start().add(self.offset) {
+            self.offset -= layout.size() - new_size;
+        }
+
+        Ok(MemoryBlock {
+            ptr,
+            size: new_size,
+        })
+    }
+}
+
+impl<const N: usize> AllocAll for StackAlloc<N> {
+    fn alloc_all(&mut self, layout: Layout, init: AllocInit) -> Result<MemoryBlock, AllocErr> {
+        if self.offset != 0 {
+            return Err(AllocErr);
+        }
+        self.alloc(layout, init)
+    }
+
+    fn dealloc_all(&mut self) {
+        self.offset = 0;
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn capacity_left(&self) -> usize {
+        N - self.offset
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset == 0
+    }
+
+    fn is_full(&self) -> bool {
+        self.offset == N
+    }
+}
+
+impl<const N: usize> Owns for StackAlloc<N> {
+    fn owns(&self, memory: MemoryBlock) -> bool {
+        let start = self.start() as usize;
+        let ptr = memory.ptr.as_ptr() as usize;
+        ptr >= start && ptr + memory.size <= start + N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StackAlloc;
+    use crate::AllocAll;
+    use std::alloc::{AllocInit, AllocRef, Layout, ReallocPlacement};
+
+    #[test]
+    fn alloc_to_capacity() {
+        let mut alloc = StackAlloc::<64>::default();
+
+        alloc
+            .alloc(Layout::new::<[u8; 32]>(), AllocInit::Uninitialized)
+            .expect("Could not allocate 32 bytes");
+        alloc
+            .alloc(Layout::new::<[u8; 32]>(), AllocInit::Uninitialized)
+            .expect("Could not allocate the remaining 32 bytes");
+
+        assert!(alloc.is_full());
+        assert_eq!(alloc.capacity_left(), 0);
+        assert!(alloc
+            .alloc(Layout::new::<u8>(), AllocInit::Uninitialized)
+            .is_err());
+    }
+
+    #[test]
+    fn grow_in_place_then_fails_once_full() {
+        let mut alloc = StackAlloc::<64>::default();
+
+        unsafe {
+            let memory = alloc
+                .alloc(Layout::new::<[u8; 16]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate 16 bytes");
+
+            let memory = alloc
+                .grow(
+                    memory.ptr,
+                    Layout::new::<[u8; 16]>(),
+                    32,
+                    ReallocPlacement::InPlace,
+                    AllocInit::Uninitialized,
+                )
+                .expect("Could not grow in place to 32 bytes");
+            assert_eq!(memory.size, 32);
+            assert_eq!(alloc.capacity_left(), 32);
+
+            // Allocate the remaining capacity, so `memory` is no longer the most recent
+            // allocation and growing it in place can no longer fit.
+            alloc
+                .alloc(Layout::new::<[u8; 32]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate the remaining 32 bytes");
+
+            assert!(alloc
+                .grow(
+                    memory.ptr,
+                    Layout::new::<[u8; 32]>(),
+                    48,
+                    ReallocPlacement::InPlace,
+                    AllocInit::Uninitialized,
+                )
+                .is_err());
+            assert!(alloc
+                .grow(
+                    memory.ptr,
+                    Layout::new::<[u8; 32]>(),
+                    48,
+                    ReallocPlacement::MayMove,
+                    AllocInit::Uninitialized,
+                )
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn dealloc_lifo_reclaims_non_lifo_is_noop() {
+        let mut alloc = StackAlloc::<64>::default();
+
+        unsafe {
+            let a = alloc
+                .alloc(Layout::new::<[u8; 16]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate `a`");
+            let b = alloc
+                .alloc(Layout::new::<[u8; 16]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate `b`");
+            assert_eq!(alloc.capacity_left(), 32);
+
+            // `a` is not the most recent allocation, so freeing it out of order is a no-op.
+            alloc.dealloc(a.ptr, Layout::new::<[u8; 16]>());
+            assert_eq!(alloc.capacity_left(), 32);
+
+            // `b` is the most recent allocation, so freeing it rewinds the bump pointer.
+            alloc.dealloc(b.ptr, Layout::new::<[u8; 16]>());
+            assert_eq!(alloc.capacity_left(), 48);
+        }
+    }
+
+    #[test]
+    fn dealloc_all_resets_the_arena() {
+        let mut alloc = StackAlloc::<64>::default();
+
+        alloc
+            .alloc(Layout::new::<[u8; 48]>(), AllocInit::Uninitialized)
+            .expect("Could not allocate 48 bytes");
+        assert!(!alloc.is_empty());
+
+        alloc.dealloc_all();
+        assert!(alloc.is_empty());
+        assert_eq!(alloc.capacity_left(), 64);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::CallbackRef;
+use alloc::vec::Vec;
+use core::{
+    alloc::{AllocErr, AllocInit, Layout, MemoryBlock, ReallocPlacement},
+    cell::{Cell, RefCell},
+    ptr::NonNull,
+};
+
+/// A [`CallbackRef`] that records live/peak byte usage and overallocation slack.
+///
+/// `BytesTracker` watches the real [`MemoryBlock::size`] returned by `alloc`/`grow`/`shrink` and
+/// maintains:
+///
+/// - the number of bytes currently live ([`live_bytes`][Self::live_bytes]),
+/// - the high-water mark of live bytes ever reached ([`peak_bytes`][Self::peak_bytes]), and
+/// - the cumulative slack between what was requested and what the allocator actually handed back
+///   ([`wasted_bytes`][Self::wasted_bytes]), since allocators are permitted to overallocate.
+///
+/// Since a `dealloc`/`shrink` call only ever carries the *requested* layout, not the size the
+/// matching `alloc`/`grow` actually granted, `BytesTracker` keeps a small side table from
+/// pointer to granted size so `live_bytes`/`peak_bytes` track reality even when the wrapped
+/// allocator overallocates.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(allocator_api)]
+///
+/// use alloc_compose::{stats::BytesTracker, Proxy};
+/// use std::alloc::{AllocInit, AllocRef, Global, Layout};
+///
+/// let tracker = BytesTracker::default();
+/// let mut alloc = Proxy {
+///     alloc: Global,
+///     callbacks: tracker.by_ref(),
+/// };
+///
+/// unsafe {
+///     let memory = alloc.alloc(Layout::new::<[u8; 64]>(), AllocInit::Uninitialized)?;
+///     assert_eq!(tracker.live_bytes(), memory.size);
+///     assert_eq!(tracker.peak_bytes(), memory.size);
+///
+///     alloc.dealloc(memory.ptr, Layout::new::<[u8; 64]>());
+///     assert_eq!(tracker.live_bytes(), 0);
+///     assert_eq!(tracker.peak_bytes(), memory.size);
+/// }
+/// # Ok::<(), core::alloc::AllocErr>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct BytesTracker {
+    live: Cell<usize>,
+    peak: Cell<usize>,
+    wasted: Cell<usize>,
+    granted_sizes: RefCell<Vec<(NonNull<u8>, usize)>>,
+}
+
+impl BytesTracker {
+    /// Returns a shared reference to `self` for use as a [`CallbackRef`].
+    pub fn by_ref(&self) -> &Self {
+        self
+    }
+
+    /// Returns the number of bytes currently live.
+    pub fn live_bytes(&self) -> usize {
+        self.live.get()
+    }
+
+    /// Returns the highest number of live bytes ever reached.
+    pub fn peak_bytes(&self) -> usize {
+        self.peak.get()
+    }
+
+    /// Returns the cumulative slack between requested and actually granted bytes.
+    pub fn wasted_bytes(&self) -> usize {
+        self.wasted.get()
+    }
+
+    fn add_live(&self, size: usize) {
+        let live = self.live.get() + size;
+        self.live.set(live);
+        if live > self.peak.get() {
+            self.peak.set(live);
+        }
+    }
+
+    fn sub_live(&self, size: usize) {
+        self.live.set(self.live.get() - size);
+    }
+
+    fn record_granted(&self, ptr: NonNull<u8>, size: usize) {
+        self.granted_sizes.borrow_mut().push((ptr, size));
+    }
+
+    /// Removes and returns the size granted for `ptr` by a prior `alloc`/`grow`/`shrink`.
+    fn take_granted(&self, ptr: NonNull<u8>) -> usize {
+        let mut granted_sizes = self.granted_sizes.borrow_mut();
+        let index = granted_sizes
+            .iter()
+            .position(|&(recorded, _)| recorded == ptr)
+            .expect("BytesTracker: dealloc/grow/shrink of an untracked pointer");
+        granted_sizes.swap_remove(index).1
+    }
+}
+
+impl CallbackRef for &BytesTracker {
+    fn after_alloc(&self, layout: Layout, _init: AllocInit, result: Result<MemoryBlock, AllocErr>) {
+        if let Ok(memory) = result {
+            self.add_live(memory.size);
+            self.wasted.set(self.wasted.get() + (memory.size - layout.size()));
+            self.record_granted(memory.ptr, memory.size);
+        }
+    }
+
+    fn after_dealloc(&self, ptr: NonNull<u8>, _layout: Layout) {
+        self.sub_live(self.take_granted(ptr));
+    }
+
+    fn after_grow(
+        &self,
+        ptr: NonNull<u8>,
+        _layout: Layout,
+        new_size: usize,
+        _placement: ReallocPlacement,
+        _init: AllocInit,
+        result: Result<MemoryBlock, AllocErr>,
+    ) {
+        if let Ok(memory) = result {
+            self.sub_live(self.take_granted(ptr));
+            self.add_live(memory.size);
+            self.wasted.set(self.wasted.get() + (memory.size - new_size));
+            self.record_granted(memory.ptr, memory.size);
+        }
+    }
+
+    fn after_shrink(
+        &self,
+        ptr: NonNull<u8>,
+        _layout: Layout,
+        new_size: usize,
+        _placement: ReallocPlacement,
+        result: Result<MemoryBlock, AllocErr>,
+    ) {
+        if let Ok(memory) = result {
+            self.sub_live(self.take_granted(ptr));
+            self.add_live(memory.size);
+            self.wasted.set(self.wasted.get() + (memory.size - new_size));
+            self.record_granted(memory.ptr, memory.size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BytesTracker;
+    use crate::Proxy;
+    use std::alloc::{AllocErr, AllocInit, AllocRef, Layout, MemoryBlock, ReallocPlacement, System};
+    use std::ptr::NonNull;
+
+    /// An allocator that always rounds the requested size up by a fixed, deterministic amount,
+    /// so tests can exercise overallocation without depending on `System`'s actual (platform- and
+    /// size-dependent) rounding behavior.
+    struct OverAllocate<A>(A);
+
+    fn padded(layout: Layout) -> Layout {
+        Layout::from_size_align(layout.size() + 5, layout.align()).unwrap()
+    }
+
+    unsafe impl<A: AllocRef> AllocRef for OverAllocate<A> {
+        fn alloc(&mut self, layout: Layout, init: AllocInit) -> Result<MemoryBlock, AllocErr> {
+            self.0.alloc(padded(layout), init)
+        }
+
+        unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.dealloc(ptr, padded(layout))
+        }
+
+        unsafe fn grow(
+            &mut self,
+            ptr: NonNull<u8>,
+            layout: Layout,
+            new_size: usize,
+            placement: ReallocPlacement,
+            init: AllocInit,
+        ) -> Result<MemoryBlock, AllocErr> {
+            self.0
+                .grow(ptr, padded(layout), new_size + 5, placement, init)
+        }
+
+        unsafe fn shrink(
+            &mut self,
+            ptr: NonNull<u8>,
+            layout: Layout,
+            new_size: usize,
+            placement: ReallocPlacement,
+        ) -> Result<MemoryBlock, AllocErr> {
+            self.0
+                .shrink(ptr, padded(layout), new_size + 5, placement)
+        }
+    }
+
+    #[test]
+    fn tracks_real_size_through_an_overallocating_allocator() {
+        let tracker = BytesTracker::default();
+        let mut alloc = Proxy {
+            alloc: OverAllocate(System),
+            callbacks: tracker.by_ref(),
+        };
+
+        unsafe {
+            let memory = alloc
+                .alloc(Layout::new::<[u8; 3]>(), AllocInit::Uninitialized)
+                .expect("Could not allocate 3 bytes");
+            assert_eq!(memory.size, 8);
+            assert_eq!(tracker.live_bytes(), 8);
+            assert_eq!(tracker.wasted_bytes(), 5);
+
+            // A caller only ever echoes back the *requested* layout, never the granted size.
+            alloc.dealloc(memory.ptr, Layout::new::<[u8; 3]>());
+
+            assert_eq!(tracker.live_bytes(), 0);
+            assert_eq!(tracker.peak_bytes(), 8);
+        }
+    }
+}